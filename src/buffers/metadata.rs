@@ -0,0 +1,32 @@
+use crate::{BufferFlags, Timestamp};
+
+/// Per-frame metadata reported by the driver
+///
+/// Bundles the sequence number, timestamp and flags the driver reports alongside every
+/// dequeued buffer into a single, cheaply copyable value, instead of threading the three
+/// through every buffer implementation separately. This also gives downstream code a single
+/// value to clone onto an owned buffer, log or compare, and makes adding future per-frame
+/// fields (field order, bytes used, plane count) a single-site change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Metadata {
+    pub sequence: u32,
+    pub timestamp: Timestamp,
+    pub flags: BufferFlags,
+}
+
+impl Metadata {
+    /// Returns a new metadata value
+    ///
+    /// # Arguments
+    ///
+    /// * `sequence` - Sequence number as counted by the driver
+    /// * `timestamp` - Timestamp as reported by the driver
+    /// * `flags` - Flags as set by the driver
+    pub fn new(sequence: u32, timestamp: Timestamp, flags: BufferFlags) -> Self {
+        Metadata {
+            sequence,
+            timestamp,
+            flags,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Buffer, BufferFlags, Timestamp};
+use crate::{Buffer, Metadata};
 
 /// Memory mapped buffer
 ///
@@ -7,9 +7,7 @@ use crate::{Buffer, BufferFlags, Timestamp};
 /// the buffer instance.
 /// Acquiring ownership of the data in userspace is not possible, so it has to be copied.
 pub struct MappedBuffer<'a> {
-    flags: BufferFlags,
-    timestamp: Timestamp,
-    sequence: u32,
+    meta: Metadata,
 
     view: &'a [u8],
 }
@@ -23,27 +21,21 @@ impl<'a> MappedBuffer<'a> {
     /// # Arguments
     ///
     /// * `view` - Slice of raw memory
-    /// * `seq` - Sequence number as counted by the driver
-    /// * `ts` - Timestamp as reported by the driver
-    /// * `flags` - Flags as set by the driver
+    /// * `meta` - Metadata reported by the driver for this buffer
     ///
     /// # Example
     ///
     /// ```
-    /// use v4l::{BufferFlags, MappedBuffer, Timestamp};
+    /// use v4l::{BufferFlags, MappedBuffer, Metadata, Timestamp};
     ///
     /// let data: Vec<u8> = Vec::new();
     /// let ts = Timestamp::new(0 /* sec */, 0 /* usec */);
     /// let flags = BufferFlags::from(0);
-    /// let buf = MappedBuffer::new(&data, 0, ts, flags);
+    /// let meta = Metadata::new(0, ts, flags);
+    /// let buf = MappedBuffer::new(&data, meta);
     /// ```
-    pub fn new(view: &'a [u8], seq: u32, ts: Timestamp, flags: BufferFlags) -> Self {
-        MappedBuffer {
-            flags,
-            timestamp: ts,
-            sequence: seq,
-            view,
-        }
+    pub fn new(view: &'a [u8], meta: Metadata) -> Self {
+        MappedBuffer { meta, view }
     }
 }
 
@@ -60,15 +52,7 @@ impl<'a> Buffer for MappedBuffer<'a> {
         self.view.is_empty()
     }
 
-    fn seq(&self) -> u32 {
-        self.sequence
-    }
-
-    fn timestamp(&self) -> Timestamp {
-        self.timestamp
-    }
-
-    fn flags(&self) -> BufferFlags {
-        self.flags
+    fn meta(&self) -> &Metadata {
+        &self.meta
     }
 }
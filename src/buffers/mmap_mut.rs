@@ -0,0 +1,135 @@
+use std::io;
+use std::ops::{Deref, DerefMut};
+use std::os::raw::c_void;
+
+use crate::{Buffer, Metadata};
+
+/// Mutable, memory mapped buffer
+///
+/// Counterpart to [`MappedBuffer`](crate::MappedBuffer) for V4L2 output and loopback devices,
+/// where userspace has to fill an mmap'd buffer before it can be queued back to the driver.
+/// Mirrors the read-map/write-map split used by other userspace V4L2 wrappers: the same backing
+/// memory is exposed through a distinct, mutable handle instead of relaxing the read-only one.
+///
+/// [`into_raw_for_queue`](MappedBufferMut::into_raw_for_queue) is the only way to get the raw
+/// parts `VIDIOC_QBUF` needs, and it refuses to hand them back until
+/// [`set_bytes_used`](MappedBufferMut::set_bytes_used) has been called since the last write, so a
+/// caller cannot re-queue a buffer it never finished writing. Note that V4L2 `mmap()` regions are
+/// normally remapped driver/videobuf2 memory rather than page-cache-backed file mappings, so the
+/// `msync(2)` call `set_bytes_used` makes is best-effort only — on most drivers it is a no-op, and
+/// it is the bookkeeping gate above, not `msync`, that actually prevents queuing unfinished data.
+pub struct MappedBufferMut<'a> {
+    meta: Metadata,
+    bytes_used: usize,
+    flushed: bool,
+
+    view: &'a mut [u8],
+}
+
+impl<'a> MappedBufferMut<'a> {
+    /// Returns a mutable mapped memory region representation
+    ///
+    /// # Arguments
+    ///
+    /// * `view` - Mutable slice of raw memory
+    /// * `meta` - Metadata to queue the buffer with
+    pub fn new(view: &'a mut [u8], meta: Metadata) -> Self {
+        MappedBufferMut {
+            meta,
+            bytes_used: 0,
+            flushed: false,
+            view,
+        }
+    }
+
+    /// Returns a mutable view of the raw buffer memory
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        self.flushed = false;
+        self.view
+    }
+
+    /// Returns the number of bytes previously set with
+    /// [`set_bytes_used`](MappedBufferMut::set_bytes_used)
+    pub fn bytes_used(&self) -> usize {
+        self.bytes_used
+    }
+
+    /// Tells the driver how many bytes of the mapped region hold valid data, and marks the
+    /// buffer ready to be re-queued
+    ///
+    /// Also issues an `msync(2)` on the mapped region. Most V4L2 drivers back `mmap()` buffers
+    /// with remapped DMA memory rather than the page cache, where `msync` is a no-op. If `msync`
+    /// fails, its error is returned and the buffer is not marked ready, so
+    /// [`into_raw_for_queue`](MappedBufferMut::into_raw_for_queue) keeps refusing it.
+    pub fn set_bytes_used(&mut self, len: usize) -> io::Result<()> {
+        assert!(
+            len <= self.view.len(),
+            "bytes used exceeds the mapped region"
+        );
+        self.bytes_used = len;
+
+        let ret = unsafe {
+            libc::msync(
+                self.view.as_mut_ptr() as *mut c_void,
+                self.view.len(),
+                libc::MS_SYNC,
+            )
+        };
+        if ret != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        self.flushed = true;
+        Ok(())
+    }
+
+    /// Overwrites the sequence, timestamp and flags queued alongside this buffer
+    pub fn set_meta(&mut self, meta: Metadata) {
+        self.meta = meta;
+    }
+
+    /// Returns the raw pointer and length `VIDIOC_QBUF` needs, consuming the buffer
+    ///
+    /// Returns `Err(self)` if [`set_bytes_used`](MappedBufferMut::set_bytes_used) was never
+    /// called (or the view was mutated again afterwards), since the write map would not be
+    /// flushed and the driver could read a partially written frame.
+    pub fn into_raw_for_queue(self) -> Result<(*const u8, usize), Self> {
+        if !self.flushed {
+            return Err(self);
+        }
+        Ok((self.view.as_ptr(), self.bytes_used))
+    }
+}
+
+impl<'a> Deref for MappedBufferMut<'a> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.view
+    }
+}
+
+impl<'a> DerefMut for MappedBufferMut<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.flushed = false;
+        self.view
+    }
+}
+
+impl<'a> Buffer for MappedBufferMut<'a> {
+    fn data(&self) -> &[u8] {
+        self.view
+    }
+
+    fn len(&self) -> usize {
+        self.view.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.view.is_empty()
+    }
+
+    fn meta(&self) -> &Metadata {
+        &self.meta
+    }
+}
@@ -0,0 +1,74 @@
+mod dma;
+mod metadata;
+mod mmap;
+mod mmap_mut;
+mod owned;
+mod userptr;
+
+pub use dma::DmaBuffer;
+pub use metadata::Metadata;
+pub use mmap::MappedBuffer;
+pub use mmap_mut::MappedBufferMut;
+pub use owned::HostBuffer;
+pub use userptr::UserPtrBuffer;
+
+use crate::Timestamp;
+
+/// Flags describing the state of a dequeued buffer
+///
+/// Mirrors the `V4L2_BUF_FLAG_*` bitmask reported by the driver in `struct v4l2_buffer::flags`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BufferFlags(u32);
+
+impl From<u32> for BufferFlags {
+    fn from(flags: u32) -> Self {
+        BufferFlags(flags)
+    }
+}
+
+impl From<BufferFlags> for u32 {
+    fn from(flags: BufferFlags) -> Self {
+        flags.0
+    }
+}
+
+/// Represents a single video frame buffer
+///
+/// Implementors provide read access to the underlying frame data along with the metadata
+/// reported by the driver (sequence number, timestamp, flags).
+pub trait Buffer {
+    /// Returns the raw byte contents of the buffer
+    fn data(&self) -> &[u8];
+
+    /// Returns the number of bytes in the buffer
+    fn len(&self) -> usize;
+
+    /// Returns true if the buffer is empty
+    fn is_empty(&self) -> bool;
+
+    /// Returns the metadata reported by the driver for this buffer
+    fn meta(&self) -> &Metadata;
+
+    /// Returns the sequence number as counted by the driver
+    fn seq(&self) -> u32 {
+        self.meta().sequence
+    }
+
+    /// Returns the timestamp as reported by the driver
+    fn timestamp(&self) -> Timestamp {
+        self.meta().timestamp
+    }
+
+    /// Returns the flags as set by the driver
+    fn flags(&self) -> BufferFlags {
+        self.meta().flags
+    }
+
+    /// Copies the buffer contents and metadata into an owned, `'static` [`HostBuffer`]
+    ///
+    /// Same as `HostBuffer::from(self)`. Use it to keep a frame around past the lifetime of its
+    /// backing mmap, e.g. to hand it off to another thread or queue it for later processing.
+    fn to_owned(&self) -> HostBuffer {
+        HostBuffer::from(self)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{Buffer, Metadata};
+
+/// Owned, heap-backed buffer
+///
+/// Unlike [`MappedBuffer`](crate::MappedBuffer), a `HostBuffer` owns a copy of its bytes in a
+/// `Vec<u8>`, so it is not tied to the lifetime of the underlying mmap and can be freely moved
+/// across threads or queued onto channels.
+pub struct HostBuffer {
+    meta: Metadata,
+
+    bytes: Vec<u8>,
+}
+
+impl HostBuffer {
+    /// Returns an owned buffer backed by the given bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Owned buffer contents
+    /// * `meta` - Metadata reported by the driver for this buffer
+    pub fn new(bytes: Vec<u8>, meta: Metadata) -> Self {
+        HostBuffer { meta, bytes }
+    }
+}
+
+impl<B: Buffer + ?Sized> From<&B> for HostBuffer {
+    fn from(buf: &B) -> Self {
+        HostBuffer::new(buf.data().to_vec(), *buf.meta())
+    }
+}
+
+impl Buffer for HostBuffer {
+    fn data(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn meta(&self) -> &Metadata {
+        &self.meta
+    }
+}
@@ -0,0 +1,109 @@
+use std::alloc::{alloc_zeroed, dealloc, Layout};
+use std::ptr::NonNull;
+use std::sync::OnceLock;
+
+use crate::{Buffer, BufferFlags, Metadata, Timestamp};
+
+/// Returns the runtime page size (`sysconf(_SC_PAGESIZE)`), queried once and cached
+///
+/// Most V4L2 drivers require the userptr address and length to be page-aligned, and the page
+/// size varies across targets (e.g. 4K vs. 16K/64K on some arm64 kernels), so it cannot be
+/// assumed at compile time.
+fn page_size() -> usize {
+    static PAGE_SIZE: OnceLock<usize> = OnceLock::new();
+    *PAGE_SIZE.get_or_init(|| {
+        let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        assert!(size > 0, "sysconf(_SC_PAGESIZE) failed");
+        size as usize
+    })
+}
+
+/// User-pointer (`V4L2_MEMORY_USERPTR`) buffer
+///
+/// Unlike the MMAP buffers elsewhere in this module, a `UserPtrBuffer` allocates a page-aligned
+/// region in userspace up front and hands the driver its address, rather than mapping memory
+/// the kernel owns. The allocation is guaranteed to outlive every `VIDIOC_QBUF` / `VIDIOC_DQBUF`
+/// cycle it takes part in, since it is only freed when the `UserPtrBuffer` itself is dropped.
+pub struct UserPtrBuffer {
+    ptr: NonNull<u8>,
+    capacity: usize,
+    align: usize,
+    length: usize,
+    meta: Metadata,
+}
+
+impl UserPtrBuffer {
+    /// Allocates a new page-aligned buffer of at least `capacity` bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Minimum number of bytes the driver may write into the allocation
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "userptr buffer capacity must be non-zero");
+        let align = page_size();
+        let layout =
+            Layout::from_size_align(capacity, align).expect("invalid userptr buffer layout");
+        // SAFETY: layout is non-zero-sized and page-aligned by construction above.
+        let raw = unsafe { alloc_zeroed(layout) };
+        let ptr = NonNull::new(raw).expect("userptr buffer allocation failed");
+
+        UserPtrBuffer {
+            ptr,
+            capacity,
+            align,
+            length: 0,
+            meta: Metadata::new(0, Timestamp::new(0, 0), BufferFlags::from(0)),
+        }
+    }
+
+    /// Returns the raw pointer and capacity the ioctl layer hands to the driver as the
+    /// buffer's `m.userptr` / `length` fields
+    pub fn as_raw_parts(&self) -> (*mut u8, usize) {
+        (self.ptr.as_ptr(), self.capacity)
+    }
+
+    /// Records the result of a completed `VIDIOC_DQBUF`
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - Number of bytes the driver actually wrote
+    /// * `meta` - Metadata reported by the driver for this capture
+    pub fn set_result(&mut self, length: usize, meta: Metadata) {
+        assert!(
+            length <= self.capacity,
+            "driver wrote past the buffer capacity"
+        );
+        self.length = length;
+        self.meta = meta;
+    }
+}
+
+impl Drop for UserPtrBuffer {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(self.capacity, self.align).unwrap();
+        unsafe { dealloc(self.ptr.as_ptr(), layout) };
+    }
+}
+
+// SAFETY: the allocation is owned by the buffer and freed only on drop; safe code reads it only
+// through `&self`, and the recorded length and metadata change only through `&mut self` via
+// `set_result`.
+unsafe impl Send for UserPtrBuffer {}
+
+impl Buffer for UserPtrBuffer {
+    fn data(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.length) }
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    fn meta(&self) -> &Metadata {
+        &self.meta
+    }
+}
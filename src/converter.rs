@@ -0,0 +1,162 @@
+use std::ffi::CStr;
+use std::io;
+use std::os::raw::{c_char, c_int};
+
+use crate::format::RawFormat;
+use crate::{Buffer, Format, FourCC, HostBuffer, Metadata};
+
+#[allow(non_camel_case_types)]
+enum RawConverter {}
+
+/// `struct v4l2_fmtdesc` as defined by the kernel UAPI in `videodev2.h`, used to enumerate the
+/// formats `libv4lconvert` can produce via `v4lconvert_enum_fmt`
+#[repr(C)]
+struct RawFmtDesc {
+    index: u32,
+    buf_type: u32,
+    flags: u32,
+    description: [u8; 32],
+    pixelformat: u32,
+    mbus_code: u32,
+    reserved: [u32; 3],
+}
+
+extern "C" {
+    fn v4lconvert_create(fd: c_int) -> *mut RawConverter;
+    fn v4lconvert_destroy(data: *mut RawConverter);
+    fn v4lconvert_convert(
+        data: *mut RawConverter,
+        src_fmt: *const RawFormat,
+        dest_fmt: *const RawFormat,
+        src: *const u8,
+        src_size: u32,
+        dest: *mut u8,
+        dest_size: u32,
+    ) -> c_int;
+    fn v4lconvert_get_error_message(data: *mut RawConverter) -> *const c_char;
+    fn v4lconvert_enum_fmt(data: *mut RawConverter, fmt: *mut RawFmtDesc) -> c_int;
+}
+
+/// On-the-fly pixel format conversion backed by `libv4lconvert`
+///
+/// Many cameras only emit compressed or chroma-subsampled formats such as MJPG or YUYV. A
+/// `Converter` negotiates a source and destination [`Format`] once and then repeatedly
+/// decodes/re-samples frames between them, handing back an owned [`HostBuffer`] so the result
+/// can be queued, stored or handed to another thread like any other buffer.
+pub struct Converter {
+    handle: *mut RawConverter,
+    src_fmt: Format,
+    dest_fmt: Format,
+    dest: Vec<u8>,
+}
+
+impl Converter {
+    /// Creates a converter between a source and destination pixel format
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor of the device the source format was negotiated on
+    /// * `src_fmt` - Format the driver actually produces
+    /// * `dest_fmt` - Format callers want to receive from [`convert`](Converter::convert)
+    pub fn new(fd: c_int, src_fmt: Format, dest_fmt: Format) -> io::Result<Self> {
+        let handle = unsafe { v4lconvert_create(fd) };
+        if handle.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Converter {
+            handle,
+            src_fmt,
+            dest_fmt,
+            dest: vec![0; dest_fmt.size() as usize],
+        })
+    }
+
+    /// Converts a single buffer from the source format into the destination format
+    ///
+    /// The returned [`HostBuffer`] carries the original sequence number, timestamp and flags.
+    pub fn convert(&mut self, src: &impl Buffer) -> io::Result<HostBuffer> {
+        let written = unsafe {
+            v4lconvert_convert(
+                self.handle,
+                self.src_fmt.as_raw(),
+                self.dest_fmt.as_raw(),
+                src.data().as_ptr(),
+                src.data().len() as u32,
+                self.dest.as_mut_ptr(),
+                self.dest.len() as u32,
+            )
+        };
+        if written < 0 {
+            return Err(self.last_error());
+        }
+
+        Ok(HostBuffer::new(
+            self.dest[..written as usize].to_vec(),
+            Metadata::new(src.seq(), src.timestamp(), src.flags()),
+        ))
+    }
+
+    /// Lists the FourCCs `libv4lconvert` can deliver on the device for the buffer type of
+    /// `src_fmt`
+    ///
+    /// The list holds the device's native formats together with the formats `libv4lconvert` can
+    /// convert them to. Only the buffer type of `src_fmt` is used; the list is not narrowed to
+    /// the formats reachable from its pixel format.
+    ///
+    /// # Arguments
+    ///
+    /// * `fd` - File descriptor of the device `src_fmt` was negotiated on; `libv4lconvert`
+    ///   queries the device's own quirks/format table through this fd, so the fd must be the
+    ///   real device, not a throwaway one
+    /// * `src_fmt` - Format the driver actually produces; only its buffer type is read
+    pub fn reachable_formats(fd: c_int, src_fmt: &Format) -> io::Result<Vec<FourCC>> {
+        // A throwaway converter handle is fine here; v4lconvert_enum_fmt does not dequeue or
+        // otherwise touch the device beyond the ioctls it issues on `fd`.
+        let handle = unsafe { v4lconvert_create(fd) };
+        if handle.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut formats = Vec::new();
+        let mut index = 0;
+        loop {
+            let mut desc = RawFmtDesc {
+                index,
+                buf_type: src_fmt.buf_type(),
+                flags: 0,
+                description: [0; 32],
+                pixelformat: 0,
+                mbus_code: 0,
+                reserved: [0; 3],
+            };
+            let ret = unsafe { v4lconvert_enum_fmt(handle, &mut desc) };
+            if ret < 0 {
+                break;
+            }
+            formats.push(FourCC::from(desc.pixelformat));
+            index += 1;
+        }
+
+        unsafe { v4lconvert_destroy(handle) };
+        Ok(formats)
+    }
+
+    fn last_error(&self) -> io::Error {
+        let msg = unsafe {
+            let ptr = v4lconvert_get_error_message(self.handle);
+            if ptr.is_null() {
+                "unknown libv4lconvert error".to_string()
+            } else {
+                CStr::from_ptr(ptr).to_string_lossy().into_owned()
+            }
+        };
+        io::Error::other(msg)
+    }
+}
+
+impl Drop for Converter {
+    fn drop(&mut self) {
+        unsafe { v4lconvert_destroy(self.handle) };
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt;
+
+/// `V4L2_BUF_TYPE_VIDEO_CAPTURE` from `videodev2.h`
+pub const V4L2_BUF_TYPE_VIDEO_CAPTURE: u32 = 1;
+
+/// Four-character code identifying a pixel format (e.g. `b"YUYV"`, `b"MJPG"`)
+///
+/// Corresponds to the `pixelformat` field of `struct v4l2_pix_format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FourCC(u32);
+
+impl From<u32> for FourCC {
+    fn from(code: u32) -> Self {
+        FourCC(code)
+    }
+}
+
+impl From<FourCC> for u32 {
+    fn from(fourcc: FourCC) -> Self {
+        fourcc.0
+    }
+}
+
+/// Single-planar `struct v4l2_pix_format` as defined by the kernel UAPI in `videodev2.h`
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct RawPixFormat {
+    pub width: u32,
+    pub height: u32,
+    pub pixelformat: u32,
+    pub field: u32,
+    pub bytesperline: u32,
+    pub sizeimage: u32,
+    pub colorspace: u32,
+    pub priv_: u32,
+    pub flags: u32,
+    pub ycbcr_enc: u32,
+    pub quantization: u32,
+    pub xfer_func: u32,
+}
+
+/// `fmt` union of `struct v4l2_format`
+///
+/// The kernel reserves `raw_data[200]` inside this union as an ABI-stable upper bound covering
+/// every format variant (`pix`, `pix_mp`, `win`, ...), so capping it here at 200 bytes keeps the
+/// layout correct even for variants this crate never constructs itself. The zero-sized `_align`
+/// member gives the union pointer alignment, as the pointers inside `win` do in C, so `fmt`
+/// starts at the same offset as in the kernel struct.
+#[repr(C)]
+#[derive(Clone, Copy)]
+union RawFormatUnion {
+    pix: RawPixFormat,
+    raw_data: [u8; 200],
+    _align: [usize; 0],
+}
+
+/// `struct v4l2_format` as defined by the kernel UAPI in `videodev2.h`
+///
+/// This is the real struct `libv4lconvert` reads and writes across the FFI boundary in
+/// [`Converter`](crate::Converter) — unlike a hand-rolled `{width, height, fourcc, size}`
+/// lookalike, its size and field offsets match what the C library expects.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct RawFormat {
+    pub buf_type: u32,
+    fmt: RawFormatUnion,
+}
+
+/// Pixel format negotiated with a device
+///
+/// Safe, `Copy`able wrapper around [`RawFormat`] for the single-planar
+/// `V4L2_BUF_TYPE_VIDEO_{CAPTURE,OUTPUT}` case `libv4lconvert` operates on.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Format(RawFormat);
+
+impl Format {
+    /// Returns a new single-planar capture format description
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width in pixels
+    /// * `height` - Height in pixels
+    /// * `fourcc` - Pixel format
+    /// * `size` - Size in bytes of a single frame in this format
+    pub fn new(width: u32, height: u32, fourcc: FourCC, size: u32) -> Self {
+        Format(RawFormat {
+            buf_type: V4L2_BUF_TYPE_VIDEO_CAPTURE,
+            fmt: RawFormatUnion {
+                pix: RawPixFormat {
+                    width,
+                    height,
+                    pixelformat: fourcc.into(),
+                    field: 0,
+                    bytesperline: 0,
+                    sizeimage: size,
+                    colorspace: 0,
+                    priv_: 0,
+                    flags: 0,
+                    ycbcr_enc: 0,
+                    quantization: 0,
+                    xfer_func: 0,
+                },
+            },
+        })
+    }
+
+    /// Returns the `v4l2_buf_type` this format was negotiated for
+    pub fn buf_type(&self) -> u32 {
+        self.0.buf_type
+    }
+
+    /// Returns the width in pixels
+    pub fn width(&self) -> u32 {
+        unsafe { self.0.fmt.pix.width }
+    }
+
+    /// Returns the height in pixels
+    pub fn height(&self) -> u32 {
+        unsafe { self.0.fmt.pix.height }
+    }
+
+    /// Returns the pixel format
+    pub fn fourcc(&self) -> FourCC {
+        FourCC::from(unsafe { self.0.fmt.pix.pixelformat })
+    }
+
+    /// Returns the size in bytes of a single frame in this format
+    pub fn size(&self) -> u32 {
+        unsafe { self.0.fmt.pix.sizeimage }
+    }
+
+    /// Returns a pointer to the raw `v4l2_format` for passing across the `libv4lconvert` FFI
+    /// boundary
+    pub(crate) fn as_raw(&self) -> *const RawFormat {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Format")
+            .field("width", &self.width())
+            .field("height", &self.height())
+            .field("fourcc", &self.fourcc())
+            .field("size", &self.size())
+            .finish()
+    }
+}
@@ -0,0 +1,14 @@
+//! Safe bindings to the Video4Linux2 (V4L2) userspace API
+
+mod buffers;
+mod converter;
+mod format;
+mod timestamp;
+
+pub use buffers::{
+    Buffer, BufferFlags, DmaBuffer, HostBuffer, MappedBuffer, MappedBufferMut, Metadata,
+    UserPtrBuffer,
+};
+pub use converter::Converter;
+pub use format::{Format, FourCC};
+pub use timestamp::Timestamp;
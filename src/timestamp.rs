@@ -0,0 +1,30 @@
+/// Timestamp attached to a buffer by the driver
+///
+/// Corresponds to the `struct timeval` stored in `struct v4l2_buffer::timestamp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Timestamp {
+    sec: i64,
+    usec: i64,
+}
+
+impl Timestamp {
+    /// Returns a new timestamp
+    ///
+    /// # Arguments
+    ///
+    /// * `sec` - Seconds
+    /// * `usec` - Microseconds
+    pub fn new(sec: i64, usec: i64) -> Self {
+        Timestamp { sec, usec }
+    }
+
+    /// Returns the whole seconds component
+    pub fn sec(&self) -> i64 {
+        self.sec
+    }
+
+    /// Returns the microseconds component
+    pub fn usec(&self) -> i64 {
+        self.usec
+    }
+}